@@ -12,6 +12,8 @@ pub mod morse {
     // Since most of this program uses the `String` type it cannot run on some integrated chips
     // that do not have heap allocation
 
+    use std::fmt;
+
     /// Enum representing the "symbols" of morse code. Designed after description
     /// [here](https://en.wikipedia.org/wiki/Morse_code) for international morse code
     #[derive(Clone, PartialEq, Eq, Debug)]
@@ -42,14 +44,15 @@ pub mod morse {
             }
         }
 
-        fn from_str(symbol: &str) -> Self {
+        /// Returns `None` rather than panicking so callers can report the offending token
+        fn from_str(symbol: &str) -> Option<Self> {
             match symbol {
-                "·" => Morse::Dit,
-                "―" => Morse::Dah,
-                " " => Morse::BaseSpace,
-                "   " => Morse::LetterSpace,
-                "       " => Morse::WordSpace,
-                _ => unreachable!("Morse should not contain other str sequences")
+                "·" => Some(Morse::Dit),
+                "―" => Some(Morse::Dah),
+                " " => Some(Morse::BaseSpace),
+                "   " => Some(Morse::LetterSpace),
+                "       " => Some(Morse::WordSpace),
+                _ => None
             }
         }
 
@@ -65,47 +68,86 @@ pub mod morse {
         }
     }
 
-    static CHAR_MORSE_MAP: [(char, &[Morse]); 37] = [ // needs to be &[···] since length of
-                                                   // different entries varies
-        (' ', &[Morse::WordSpace]),
-        ('a', &[Morse::Dit, Morse::Dah]),
-        ('b', &[Morse::Dah, Morse::Dit, Morse::Dit, Morse::Dit]),
-        ('c', &[Morse::Dah, Morse::Dit, Morse::Dah, Morse::Dit]),
-        ('d', &[Morse::Dah, Morse::Dit, Morse::Dit]),
-        ('e', &[Morse::Dit]),
-        ('f', &[Morse::Dit, Morse::Dit, Morse::Dah, Morse::Dit]),
-        ('g', &[Morse::Dah, Morse::Dah, Morse::Dit]),
-        ('h', &[Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit]),
-        ('i', &[Morse::Dit, Morse::Dit]),
-        ('j', &[Morse::Dit, Morse::Dah, Morse::Dah, Morse::Dah]),
-        ('k', &[Morse::Dah, Morse::Dit, Morse::Dah]),
-        ('l', &[Morse::Dit, Morse::Dah, Morse::Dit, Morse::Dit]),
-        ('m', &[Morse::Dah, Morse::Dah]),
-        ('n', &[Morse::Dah, Morse::Dit]),
-        ('o', &[Morse::Dah, Morse::Dah, Morse::Dah]),
-        ('p', &[Morse::Dit, Morse::Dah, Morse::Dah, Morse::Dit]),
-        ('q', &[Morse::Dah, Morse::Dah, Morse::Dit, Morse::Dah]),
-        ('r', &[Morse::Dit, Morse::Dah, Morse::Dit]),
-        ('s', &[Morse::Dit, Morse::Dit, Morse::Dit]),
-        ('t', &[Morse::Dah]),
-        ('u', &[Morse::Dit, Morse::Dit, Morse::Dah]),
-        ('v', &[Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dah]),
-        ('w', &[Morse::Dit, Morse::Dah, Morse::Dah]),
-        ('x', &[Morse::Dah, Morse::Dit, Morse::Dit, Morse::Dah]),
-        ('y', &[Morse::Dah, Morse::Dit, Morse::Dah, Morse::Dah]),
-        ('z', &[Morse::Dah, Morse::Dah, Morse::Dit, Morse::Dit]),
-        ('1', &[Morse::Dit, Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dah]),
-        ('2', &[Morse::Dit, Morse::Dit, Morse::Dah, Morse::Dah, Morse::Dah]),
-        ('3', &[Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dah, Morse::Dah]),
-        ('4', &[Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dah]),
-        ('5', &[Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit]),
-        ('6', &[Morse::Dah, Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit]),
-        ('7', &[Morse::Dah, Morse::Dah, Morse::Dit, Morse::Dit, Morse::Dit]),
-        ('8', &[Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dit, Morse::Dit]),
-        ('9', &[Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dit]),
-        ('0', &[Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dah]),
+    /// Maps each recognized token to its morse pattern. Most entries are single ASCII letters,
+    /// digits or the space, but a token may be any string: international punctuation is one
+    /// multi-element pattern under a single-character token, and prosigns (`<AR>`, `<SK>`,
+    /// `<BT>`, ...) are multi-character tokens whose pattern is the run-together concatenation of
+    /// their component letters, with no `LetterSpace` between them. Being a slice rather than a
+    /// fixed-size array means new entries never require updating a hardcoded length.
+    static TOKEN_MORSE_MAP: &[(&str, &[Morse])] = &[
+        (" ", &[Morse::WordSpace]),
+        ("a", &[Morse::Dit, Morse::Dah]),
+        ("b", &[Morse::Dah, Morse::Dit, Morse::Dit, Morse::Dit]),
+        ("c", &[Morse::Dah, Morse::Dit, Morse::Dah, Morse::Dit]),
+        ("d", &[Morse::Dah, Morse::Dit, Morse::Dit]),
+        ("e", &[Morse::Dit]),
+        ("f", &[Morse::Dit, Morse::Dit, Morse::Dah, Morse::Dit]),
+        ("g", &[Morse::Dah, Morse::Dah, Morse::Dit]),
+        ("h", &[Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit]),
+        ("i", &[Morse::Dit, Morse::Dit]),
+        ("j", &[Morse::Dit, Morse::Dah, Morse::Dah, Morse::Dah]),
+        ("k", &[Morse::Dah, Morse::Dit, Morse::Dah]),
+        ("l", &[Morse::Dit, Morse::Dah, Morse::Dit, Morse::Dit]),
+        ("m", &[Morse::Dah, Morse::Dah]),
+        ("n", &[Morse::Dah, Morse::Dit]),
+        ("o", &[Morse::Dah, Morse::Dah, Morse::Dah]),
+        ("p", &[Morse::Dit, Morse::Dah, Morse::Dah, Morse::Dit]),
+        ("q", &[Morse::Dah, Morse::Dah, Morse::Dit, Morse::Dah]),
+        ("r", &[Morse::Dit, Morse::Dah, Morse::Dit]),
+        ("s", &[Morse::Dit, Morse::Dit, Morse::Dit]),
+        ("t", &[Morse::Dah]),
+        ("u", &[Morse::Dit, Morse::Dit, Morse::Dah]),
+        ("v", &[Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dah]),
+        ("w", &[Morse::Dit, Morse::Dah, Morse::Dah]),
+        ("x", &[Morse::Dah, Morse::Dit, Morse::Dit, Morse::Dah]),
+        ("y", &[Morse::Dah, Morse::Dit, Morse::Dah, Morse::Dah]),
+        ("z", &[Morse::Dah, Morse::Dah, Morse::Dit, Morse::Dit]),
+        ("1", &[Morse::Dit, Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dah]),
+        ("2", &[Morse::Dit, Morse::Dit, Morse::Dah, Morse::Dah, Morse::Dah]),
+        ("3", &[Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dah, Morse::Dah]),
+        ("4", &[Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dah]),
+        ("5", &[Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit]),
+        ("6", &[Morse::Dah, Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit]),
+        ("7", &[Morse::Dah, Morse::Dah, Morse::Dit, Morse::Dit, Morse::Dit]),
+        ("8", &[Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dit, Morse::Dit]),
+        ("9", &[Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dit]),
+        ("0", &[Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dah, Morse::Dah]),
+        // International punctuation
+        (".", &[Morse::Dit, Morse::Dah, Morse::Dit, Morse::Dah, Morse::Dit, Morse::Dah]),
+        (",", &[Morse::Dah, Morse::Dah, Morse::Dit, Morse::Dit, Morse::Dah, Morse::Dah]),
+        ("?", &[Morse::Dit, Morse::Dit, Morse::Dah, Morse::Dah, Morse::Dit, Morse::Dit]),
+        ("/", &[Morse::Dah, Morse::Dit, Morse::Dit, Morse::Dah, Morse::Dit]),
+        // Prosigns: letters run together with no LetterSpace, by convention written lowercase to
+        // match the rest of this table since input is lowercased before lookup
+        ("<ar>", &[Morse::Dit, Morse::Dah, Morse::Dit, Morse::Dah, Morse::Dit]),
+        ("<sk>", &[Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dah, Morse::Dit, Morse::Dah]),
+        ("<bt>", &[Morse::Dah, Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dah]),
+    ];
+
+    /// Placeholder morse spliced in for an unmappable input character in lossy encode mode,
+    /// mirroring the `UNKNOWN_CHARACTER = "........"` convention (eight dits, signalling "error,
+    /// resync") used by reference morse encoders.
+    const UNKNOWN_MORSE: &[Morse] = &[
+        Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit,
+        Morse::Dit, Morse::Dit, Morse::Dit, Morse::Dit,
     ];
 
+    /// Names the offending character or morse token and its byte offset into the input that made
+    /// translation fail.
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub struct TranslateError {
+        pub token: String,
+        pub byte_offset: usize,
+    }
+
+    impl fmt::Display for TranslateError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "unrecognized morse token {:?} at byte offset {}", self.token, self.byte_offset)
+        }
+    }
+
+    impl std::error::Error for TranslateError {}
+
     /// Converts a morse letter to its string representation
     fn morse_char_to_str(c: &[Morse]) -> String {
         let morse: Vec<&str> = c.iter()
@@ -114,62 +156,578 @@ pub mod morse {
         morse.join(Morse::BaseSpace.to_str())
     }
 
-    /// Takes a string of a morse letter and returns it as a `Morse` Vec
-    fn from_morse_str_to_morse_array(msg: &str) -> Vec<Morse> {
-        // Collecting into a vector since dynamically creating arrays from iterators in rust isn't
-        // really a thing
-        let morse: Vec<Morse> = msg.split(Morse::BaseSpace.to_str())
-            .map(Morse::from_str)
-            .collect();
-        return morse
+    fn token_to_morse(token: &str) -> Option<&'static [Morse]> {
+        TOKEN_MORSE_MAP.iter().find(|k| k.0 == token).map(|m| m.1)
     }
 
-    fn from_morse_array_to_char(morse: &[Morse]) -> char {
-        let c: Vec<char> = CHAR_MORSE_MAP.iter()
-            .filter(|m| m.1 == morse)
-            .map(|m| m.0)
-            .collect();
-        return c.first().unwrap().to_owned() // Cloning a char and passing a pointer is basically the same on 64-bit systems
+    fn token_from_morse_pattern(morse: &[Morse]) -> Option<&'static str> {
+        TOKEN_MORSE_MAP.iter().find(|m| m.1 == morse).map(|m| m.0)
+    }
+
+    /// Splits a word into the tokens `TOKEN_MORSE_MAP` is keyed by: a `<...>` span (a prosign like
+    /// `<ar>`) is kept together as one token, everything else is a single character. Each token
+    /// remains a subslice of `word`, so byte offsets can still be computed by pointer arithmetic.
+    fn tokenize_word(word: &str) -> Vec<&str> {
+        let mut tokens = Vec::new();
+        let mut rest = word;
+        while !rest.is_empty() {
+            let token_len = if rest.starts_with('<') {
+                match rest.find('>') {
+                    Some(end) => end + 1,
+                    None => rest.chars().next().unwrap().len_utf8(),
+                }
+            } else {
+                rest.chars().next().unwrap().len_utf8()
+            };
+            let (token, remainder) = rest.split_at(token_len);
+            tokens.push(token);
+            rest = remainder;
+        }
+        tokens
+    }
+
+    /// Renders a morse pattern back to dot/dash text for use in error messages.
+    fn morse_pattern_to_string(pattern: &[Morse]) -> String {
+        pattern.iter().map(|s| match s {
+            Morse::Dit => '.',
+            Morse::Dah => '-',
+            _ => '?',
+        }).collect()
+    }
+
+    /// Builds the raw per-symbol `Morse` stream for a single word: `Dit`/`Dah` runs for each
+    /// letter, separated by `LetterSpace` between letters. No `BaseSpace` is inserted here; that
+    /// is purely a rendering concern of `morse_symbols_to_str`. `word` must be a subslice of
+    /// `msg`, so a bad character's byte offset can be computed by pointer arithmetic.
+    fn word_to_morse_symbols(msg: &str, word: &str) -> Result<Vec<Morse>, TranslateError> {
+        let word_offset = word.as_ptr() as usize - msg.as_ptr() as usize;
+        let mut symbols: Vec<Morse> = Vec::new();
+        for (i, token) in tokenize_word(word).into_iter().enumerate() {
+            if i > 0 {
+                symbols.push(Morse::LetterSpace);
+            }
+            let local_offset = token.as_ptr() as usize - word.as_ptr() as usize;
+            match token_to_morse(token) {
+                Some(m) => symbols.extend_from_slice(m),
+                None => return Err(TranslateError { token: token.to_string(), byte_offset: word_offset + local_offset }),
+            }
+        }
+        Ok(symbols)
+    }
+
+    /// Lenient counterpart of `word_to_morse_symbols`: splices `placeholder` in for any token
+    /// with no morse mapping instead of failing.
+    fn word_to_morse_symbols_lossy(word: &str, placeholder: &[Morse]) -> Vec<Morse> {
+        let mut symbols: Vec<Morse> = Vec::new();
+        for (i, token) in tokenize_word(word).into_iter().enumerate() {
+            if i > 0 {
+                symbols.push(Morse::LetterSpace);
+            }
+            symbols.extend_from_slice(token_to_morse(token).unwrap_or(placeholder));
+        }
+        symbols
+    }
+
+    /// Builds the raw per-symbol `Morse` stream for a whole message, with `WordSpace` separating
+    /// words. This is the representation both the Unicode renderer and the audio synthesizer walk.
+    fn message_to_morse_symbols(msg: &str) -> Result<Vec<Morse>, TranslateError> {
+        let mut symbols: Vec<Morse> = Vec::new();
+        for (i, word) in msg.split(' ').enumerate() {
+            if i > 0 {
+                symbols.push(Morse::WordSpace);
+            }
+            symbols.extend(word_to_morse_symbols(msg, word)?);
+        }
+        Ok(symbols)
+    }
+
+    /// Lenient counterpart of `message_to_morse_symbols`: never fails, splicing `placeholder` in
+    /// for any unmappable character.
+    fn message_to_morse_symbols_lossy(msg: &str, placeholder: &[Morse]) -> Vec<Morse> {
+        let mut symbols: Vec<Morse> = Vec::new();
+        for (i, word) in msg.split(' ').enumerate() {
+            if i > 0 {
+                symbols.push(Morse::WordSpace);
+            }
+            symbols.extend(word_to_morse_symbols_lossy(word, placeholder));
+        }
+        symbols
     }
 
-    fn word_to_morse(word: &str) -> String {
-        let mut morse_word: Vec<String> = Vec::with_capacity(128);
-        for c in word.chars() {
-            let char_morse: Vec<&[Morse]> = CHAR_MORSE_MAP.iter()
-                .filter(|k| k.0 == c)
-                .map(|m| m.1)
-                .collect();
-            morse_word.push(morse_char_to_str(char_morse.first().unwrap()));
+    /// Renders a symbol stream produced by `message_to_morse_symbols` to the Unicode dot/dash
+    /// string, inserting a `BaseSpace` between consecutive keyed (`Dit`/`Dah`) symbols.
+    fn morse_symbols_to_str(symbols: &[Morse]) -> String {
+        let mut parts: Vec<&str> = Vec::with_capacity(symbols.len() * 2);
+        for (i, symbol) in symbols.iter().enumerate() {
+            if i > 0 {
+                let prev = &symbols[i - 1];
+                if matches!(prev, Morse::Dit | Morse::Dah) && matches!(symbol, Morse::Dit | Morse::Dah) {
+                    parts.push(Morse::BaseSpace.to_str());
+                }
+            }
+            parts.push(symbol.to_str());
+        }
+        parts.concat()
+    }
+
+    /// Selects how morse is rendered to and parsed from text.
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    pub enum Notation {
+        /// The crate's original `·`/`―` rendering, with a single space between elements
+        Unicode,
+        /// Standard ASCII morse: `.`/`-` run together within a letter, letters separated by a
+        /// single space, words by `/`
+        DitDah,
+        /// One bit per morse time unit (key-down = `1`, key-up = `0`), packed into bytes and
+        /// hex-encoded so the result still fits in a `String`. `big_endian` selects whether each
+        /// byte is packed MSB-first (`true`, the classic convention) or LSB-first (`false`).
+        Binary { big_endian: bool },
+    }
+
+    /// A single decoded letter's morse pattern together with where it started in the input, or
+    /// the parse error that prevented recovering a pattern at all (e.g. a malformed dot/dash
+    /// group). Carrying the `Result` end-to-end lets both the strict and lossy decoders share one
+    /// parser per notation.
+    struct LetterSlot {
+        offset: usize,
+        result: Result<Vec<Morse>, TranslateError>,
+    }
+
+    enum MorseToken {
+        Letter(LetterSlot),
+        WordBoundary,
+    }
+
+    /// Parses the crate's native Unicode dot/dash rendering into morse tokens.
+    fn unicode_str_to_tokens(msg: &str) -> Vec<MorseToken> {
+        let mut tokens = Vec::new();
+        for (wi, word) in msg.split(Morse::WordSpace.to_str()).enumerate() {
+            if wi > 0 {
+                tokens.push(MorseToken::WordBoundary);
+            }
+            for letter in word.split(Morse::LetterSpace.to_str()) {
+                let offset = letter.as_ptr() as usize - msg.as_ptr() as usize;
+                let result = letter.split(Morse::BaseSpace.to_str())
+                    .scan(0usize, |local_offset, sym| {
+                        let this_offset = *local_offset;
+                        *local_offset += sym.len() + Morse::BaseSpace.to_str().len();
+                        Some((this_offset, sym))
+                    })
+                    .map(|(local_offset, sym)| Morse::from_str(sym).ok_or_else(|| TranslateError {
+                        token: sym.to_string(),
+                        byte_offset: offset + local_offset,
+                    }))
+                    .collect::<Result<Vec<Morse>, TranslateError>>();
+                tokens.push(MorseToken::Letter(LetterSlot { offset, result }));
+            }
         }
-        morse_word.join(Morse::LetterSpace.to_str())
+        tokens
     }
 
-    fn morseword_to_alphabet(morseword: &str) -> String {
-        let word: Vec<String> = morseword.split(Morse::LetterSpace.to_str())
-            .map(from_morse_str_to_morse_array)
-            .map(|morse| {
-                from_morse_array_to_char(&morse[..]).to_string()
+    /// Renders a symbol stream to standard ASCII morse: `.`/`-` run together within a letter,
+    /// letters separated by a single space, words by `/`.
+    fn ditdah_symbols_to_str(symbols: &[Morse]) -> String {
+        let mut out = String::new();
+        for symbol in symbols {
+            match symbol {
+                Morse::Dit => out.push('.'),
+                Morse::Dah => out.push('-'),
+                Morse::LetterSpace => out.push(' '),
+                Morse::WordSpace => out.push_str(" / "),
+                Morse::BaseSpace => {}
+            }
+        }
+        out
+    }
+
+    /// Parses standard ASCII morse (see `ditdah_symbols_to_str`) into morse tokens.
+    fn ditdah_str_to_tokens(msg: &str) -> Vec<MorseToken> {
+        let mut tokens = Vec::new();
+        for (wi, word) in msg.split('/').enumerate() {
+            if wi > 0 {
+                tokens.push(MorseToken::WordBoundary);
+            }
+            for letter in word.split_whitespace() {
+                let offset = letter.as_ptr() as usize - msg.as_ptr() as usize;
+                let mut pattern = Vec::with_capacity(letter.len());
+                let mut result = Ok(());
+                for (local_offset, c) in letter.char_indices() {
+                    match c {
+                        '.' => pattern.push(Morse::Dit),
+                        '-' => pattern.push(Morse::Dah),
+                        _ => {
+                            result = Err(TranslateError { token: c.to_string(), byte_offset: offset + local_offset });
+                            break;
+                        }
+                    }
+                }
+                tokens.push(MorseToken::Letter(LetterSlot { offset, result: result.map(|()| pattern) }));
+            }
+        }
+        tokens
+    }
+
+    /// Expands a symbol stream to one bit per morse time unit: key-down (`Dit`/`Dah`) is `1`,
+    /// key-up (gaps, `LetterSpace`, `WordSpace`) is `0`, with the usual one-unit gap inserted
+    /// between consecutive keyed elements within a letter.
+    fn symbols_to_bits(symbols: &[Morse]) -> Vec<bool> {
+        let mut bits = Vec::new();
+        let mut prev_keyed = false;
+        for symbol in symbols {
+            let keyed = matches!(symbol, Morse::Dit | Morse::Dah);
+            if keyed && prev_keyed {
+                bits.push(false);
+            }
+            bits.extend(std::iter::repeat_n(keyed, symbol.len() as usize));
+            prev_keyed = keyed;
+        }
+        bits
+    }
+
+    /// Reconstructs morse tokens from a bit-per-unit stream produced by `symbols_to_bits`,
+    /// classifying on-runs as `Dit`/`Dah` by length and off-runs as an (implicit) inter-element
+    /// gap, a letter boundary or a word boundary. Any trailing off-run is byte-packing padding,
+    /// not a real silence, so it is discarded. Every bit pattern is well-formed, so this never
+    /// fails; an unmappable pattern only surfaces once a letter is looked up.
+    fn bits_to_tokens(bits: &[bool]) -> Vec<MorseToken> {
+        let mut runs: Vec<(bool, usize, usize)> = Vec::new();
+        for (idx, &bit) in bits.iter().enumerate() {
+            match runs.last_mut() {
+                Some(last) if last.0 == bit => last.2 += 1,
+                _ => runs.push((bit, idx, 1)),
+            }
+        }
+        if matches!(runs.last(), Some((false, _, _))) {
+            runs.pop();
+        }
+        let mut tokens = Vec::new();
+        let mut letter: Vec<Morse> = Vec::new();
+        let mut letter_offset = 0usize;
+        for (i, &(keyed, start, len)) in runs.iter().enumerate() {
+            if keyed {
+                if letter.is_empty() {
+                    letter_offset = start / 4; // 4 bits per hex digit
+                }
+                letter.push(if len >= 3 { Morse::Dah } else { Morse::Dit });
+            } else if i > 0 && len >= 3 {
+                if !letter.is_empty() {
+                    tokens.push(MorseToken::Letter(LetterSlot { offset: letter_offset, result: Ok(std::mem::take(&mut letter)) }));
+                }
+                if len >= 7 {
+                    tokens.push(MorseToken::WordBoundary);
+                }
+            }
+        }
+        if !letter.is_empty() {
+            tokens.push(MorseToken::Letter(LetterSlot { offset: letter_offset, result: Ok(letter) }));
+        }
+        tokens
+    }
+
+    /// Packs a bit-per-unit stream into bytes, MSB-first if `big_endian` else LSB-first. The
+    /// final byte is zero-padded if the bit count isn't a multiple of 8.
+    fn pack_bits(bits: &[bool], big_endian: bool) -> Vec<u8> {
+        bits.chunks(8).map(|chunk| {
+            chunk.iter().enumerate().fold(0u8, |byte, (i, &bit)| {
+                if !bit {
+                    return byte;
+                }
+                let shift = if big_endian { 7 - i } else { i };
+                byte | (1 << shift)
             })
-            .collect();
-        word.join("")
+        }).collect()
+    }
+
+    /// Inverse of `pack_bits`: expands packed bytes back into one bool per bit.
+    fn unpack_bits(bytes: &[u8], big_endian: bool) -> Vec<bool> {
+        let mut bits = Vec::with_capacity(bytes.len() * 8);
+        for &byte in bytes {
+            for i in 0..8 {
+                let shift = if big_endian { 7 - i } else { i };
+                bits.push(byte & (1 << shift) != 0);
+            }
+        }
+        bits
+    }
+
+    fn bytes_to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// Decodes a hex string into bytes, two hex digits per byte. Fails on an odd-length string
+    /// (a truncated final byte) or a non-hex digit instead of panicking, so malformed `Binary`
+    /// notation input surfaces as a `TranslateError` like any other bad morse group.
+    fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, TranslateError> {
+        if let Some((i, c)) = hex.char_indices().find(|&(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(TranslateError { token: c.to_string(), byte_offset: i });
+        }
+        if !hex.len().is_multiple_of(2) {
+            return Err(TranslateError { token: hex[hex.len() - 1..].to_string(), byte_offset: hex.len() - 1 });
+        }
+        (0..hex.len()).step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16)
+                .map_err(|_| TranslateError { token: hex[i..i + 2].to_string(), byte_offset: i }))
+            .collect()
+    }
+
+    /// Converts morse tokens back into the message they encode, stopping at the first parse
+    /// error or unmapped letter pattern.
+    fn tokens_to_message(tokens: Vec<MorseToken>) -> Result<String, TranslateError> {
+        let mut words: Vec<String> = Vec::new();
+        let mut word = String::new();
+        for token in tokens {
+            match token {
+                MorseToken::Letter(slot) => {
+                    let pattern = slot.result?;
+                    let token = token_from_morse_pattern(&pattern).ok_or_else(|| TranslateError {
+                        token: morse_pattern_to_string(&pattern),
+                        byte_offset: slot.offset,
+                    })?;
+                    word.push_str(token);
+                }
+                MorseToken::WordBoundary => words.push(std::mem::take(&mut word)),
+            }
+        }
+        words.push(word);
+        Ok(words.join(" "))
+    }
+
+    /// Lenient counterpart of `tokens_to_message`: never fails, substituting `placeholder` for
+    /// any letter that failed to parse or didn't match a known pattern.
+    fn tokens_to_message_lossy(tokens: Vec<MorseToken>, placeholder: &str) -> String {
+        let mut words: Vec<String> = Vec::new();
+        let mut word = String::new();
+        for token in tokens {
+            match token {
+                MorseToken::Letter(slot) => {
+                    match slot.result.ok().and_then(|pattern| token_from_morse_pattern(&pattern)) {
+                        Some(token) => word.push_str(token),
+                        None => word.push_str(placeholder),
+                    }
+                }
+                MorseToken::WordBoundary => words.push(std::mem::take(&mut word)),
+            }
+        }
+        words.push(word);
+        words.join(" ")
     }
 
     pub fn print_morse() {
-        CHAR_MORSE_MAP.iter().for_each(|c| println!("{}: {}", c.0, morse_char_to_str(c.1)))
+        TOKEN_MORSE_MAP.iter().for_each(|c| println!("{}: {}", c.0, morse_char_to_str(c.1)))
     }
 
-    pub fn translate_to_morse(msg: &str) -> String {
-        let msg: Vec<String> = msg.split(' ')
-            .map(word_to_morse)
-            .collect();
-        return msg.join(Morse::WordSpace.to_str())
+    pub fn translate_to_morse(msg: &str, notation: Notation) -> Result<String, TranslateError> {
+        let symbols = message_to_morse_symbols(msg)?;
+        Ok(render_symbols(&symbols, notation))
+    }
+
+    /// Lenient counterpart of `translate_to_morse`: never fails, splicing in the `UNKNOWN_MORSE`
+    /// placeholder (eight dits) for any input character with no morse mapping.
+    pub fn translate_to_morse_lossy(msg: &str, notation: Notation) -> String {
+        let symbols = message_to_morse_symbols_lossy(msg, UNKNOWN_MORSE);
+        render_symbols(&symbols, notation)
+    }
+
+    fn render_symbols(symbols: &[Morse], notation: Notation) -> String {
+        match notation {
+            Notation::Unicode => morse_symbols_to_str(symbols),
+            Notation::DitDah => ditdah_symbols_to_str(symbols),
+            Notation::Binary { big_endian } => bytes_to_hex(&pack_bits(&symbols_to_bits(symbols), big_endian)),
+        }
+    }
+
+    /// Fails only when `msg` can't be split into morse tokens at all (currently: malformed
+    /// `Binary` hex); a malformed group is instead carried as an `Err` inside its own
+    /// `LetterSlot`, and a well-formed pattern that maps to no known token is carried as `Ok`,
+    /// both resolved later by `tokens_to_message[_lossy]`.
+    fn parse_to_tokens(msg: &str, notation: Notation) -> Result<Vec<MorseToken>, TranslateError> {
+        match notation {
+            Notation::Unicode => Ok(unicode_str_to_tokens(msg)),
+            Notation::DitDah => Ok(ditdah_str_to_tokens(msg)),
+            Notation::Binary { big_endian } => {
+                let bytes = hex_to_bytes(msg)?;
+                Ok(bits_to_tokens(&unpack_bits(&bytes, big_endian)))
+            }
+        }
+    }
+
+    pub fn translate_from_morse(msg: &str, notation: Notation) -> Result<String, TranslateError> {
+        tokens_to_message(parse_to_tokens(msg, notation)?)
+    }
+
+    /// Lenient counterpart of `translate_from_morse`: never fails, substituting `placeholder` for
+    /// any morse group that doesn't parse or doesn't match a known letter (or, for `Binary`
+    /// notation, for the whole message if the hex itself is malformed), so noisy real-world morse
+    /// doesn't lose the whole message at the first bad group.
+    pub fn translate_from_morse_lossy(msg: &str, notation: Notation, placeholder: &str) -> String {
+        match parse_to_tokens(msg, notation) {
+            Ok(tokens) => tokens_to_message_lossy(tokens, placeholder),
+            Err(_) => placeholder.to_string(),
+        }
+    }
+
+    /// Length, in milliseconds, of a "dit" (one time unit) at the given words-per-minute speed,
+    /// per the PARIS standard: `dit_ms = 1200 / wpm`.
+    fn dit_duration_ms(wpm: u16) -> f32 {
+        1200.0 / wpm as f32
+    }
+
+    /// Duration, in milliseconds, of a short raised-cosine ramp applied to the start and end of
+    /// every key-down span, to avoid the audible clicks a hard on/off transition would produce.
+    const RAMP_MS: f32 = 5.0;
+
+    fn silence_samples(duration_ms: f32, sample_rate: u32) -> Vec<i16> {
+        let n = (duration_ms * sample_rate as f32 / 1000.0).round() as usize;
+        vec![0i16; n]
+    }
+
+    fn tone_samples(duration_ms: f32, sample_rate: u32, tone_hz: f32) -> Vec<i16> {
+        let n = (duration_ms * sample_rate as f32 / 1000.0).round() as usize;
+        let ramp_samples = ((RAMP_MS / 1000.0) * sample_rate as f32).round() as usize;
+        let ramp_samples = ramp_samples.min(n / 2);
+        let amplitude = i16::MAX as f32 * 0.8; // headroom so the envelope never clips
+        (0..n).map(|i| {
+            let envelope = if i < ramp_samples {
+                0.5 * (1.0 - (std::f32::consts::PI * i as f32 / ramp_samples as f32).cos())
+            } else if i >= n - ramp_samples {
+                let j = n - i - 1;
+                0.5 * (1.0 - (std::f32::consts::PI * j as f32 / ramp_samples as f32).cos())
+            } else {
+                1.0
+            };
+            let t = i as f32 / sample_rate as f32;
+            (amplitude * envelope * (2.0 * std::f32::consts::PI * tone_hz * t).sin()).round() as i16
+        }).collect()
+    }
+
+    /// Renders `msg` into CW (continuous-wave) audio samples: a sine tone at `tone_hz` keyed on
+    /// and off per the morse encoding of `msg`, timed at `wpm` words per minute, at `sample_rate`
+    /// samples per second. One `Dit` is one time unit key-down, one `Dah` is three units
+    /// key-down, with a one-unit silent gap inserted between consecutive keyed elements within a
+    /// letter; `LetterSpace` and `WordSpace` render as their usual three and seven units of
+    /// silence. Start and end of every key-down span are shaped with a short raised-cosine ramp.
+    /// Unmappable characters are rendered as the same placeholder `translate_to_morse_lossy` uses,
+    /// so a bad character never aborts the rendering.
+    pub fn synthesize(msg: &str, wpm: u16, tone_hz: f32, sample_rate: u32) -> Vec<i16> {
+        let symbols = message_to_morse_symbols_lossy(msg, UNKNOWN_MORSE);
+        let dit_ms = dit_duration_ms(wpm);
+        let mut samples: Vec<i16> = Vec::new();
+        let mut prev_keyed = false;
+        for symbol in &symbols {
+            let keyed = matches!(symbol, Morse::Dit | Morse::Dah);
+            if keyed && prev_keyed {
+                samples.extend(silence_samples(dit_ms, sample_rate));
+            }
+            let duration_ms = dit_ms * symbol.len() as f32;
+            if keyed {
+                samples.extend(tone_samples(duration_ms, sample_rate, tone_hz));
+            } else {
+                samples.extend(silence_samples(duration_ms, sample_rate));
+            }
+            prev_keyed = keyed;
+        }
+        samples
     }
 
-    pub fn translate_from_morse(msg: &str) -> String {
-        let msg: Vec<String> = msg.split(Morse::WordSpace.to_str())
-            .map(morseword_to_alphabet)
+    /// Substituted for a keyed run whose classified `Dit`/`Dah` pattern matches no known token
+    /// when decoding raw timings, mirroring the CLI's own default `--placeholder` value.
+    const TIMING_PLACEHOLDER: &str = "?";
+
+    /// Splits `values` into up to `k` clusters by repeatedly cutting at the largest gaps between
+    /// consecutive sorted values (the classic "split at the biggest jump" approximation of 1-D
+    /// k-means), returning the up-to-`k - 1` thresholds between them in ascending order. Falls
+    /// back to fewer thresholds (merging clusters) when there aren't enough distinct values to
+    /// support the requested split, rather than failing.
+    fn cluster_thresholds(values: &[u32], k: usize) -> Vec<u32> {
+        if values.is_empty() || k <= 1 {
+            return Vec::new();
+        }
+        let mut sorted = values.to_vec();
+        sorted.sort_unstable();
+        let mut gaps: Vec<(usize, u32)> = (0..sorted.len() - 1)
+            .map(|i| (i, sorted[i + 1] - sorted[i]))
+            .filter(|&(_, gap)| gap > 0) // equal neighbours are never a real cluster boundary
             .collect();
-        msg.join(" ")
+        gaps.sort_by_key(|&(_, gap)| std::cmp::Reverse(gap));
+        gaps.truncate(k - 1);
+        let mut split_indices: Vec<usize> = gaps.into_iter().map(|(i, _)| i).collect();
+        split_indices.sort_unstable();
+        split_indices.into_iter().map(|i| (sorted[i] + sorted[i + 1]) / 2).collect()
+    }
+
+    /// Classifies `value` against ascending `thresholds`, returning how many it meets or exceeds
+    /// (the index of its cluster, 0-based).
+    fn classify(value: u32, thresholds: &[u32]) -> usize {
+        thresholds.iter().filter(|&&t| value >= t).count()
+    }
+
+    /// Distinguishes a `Dit` from a `Dah` key-down duration. When `thresholds` is non-empty the
+    /// two clusters found among the observed key-down durations settle it. Otherwise every
+    /// observed key-down run was the same length (e.g. "mom" is all dahs, "sos" is all dits), so
+    /// there's no cluster to split on; fall back to the classic ~1:3 dit/dah ratio against the
+    /// shortest observed key-up gap, since an inter-element gap (within a multi-symbol letter) is
+    /// always exactly one unit and so gives an absolute reference even when the key-down
+    /// durations alone can't. With no key-up gaps either (a single-element message), there's no
+    /// reference at all, so the run is reported as a `Dit`.
+    fn classify_dit_dah(duration: u32, thresholds: &[u32], key_up_durations: &[u32]) -> Morse {
+        if !thresholds.is_empty() {
+            return if classify(duration, thresholds) >= 1 { Morse::Dah } else { Morse::Dit };
+        }
+        match key_up_durations.iter().copied().min() {
+            Some(unit_ms) if duration >= unit_ms * 2 => Morse::Dah,
+            _ => Morse::Dit,
+        }
+    }
+
+    /// Looks up `letter`'s accumulated `Dit`/`Dah` pattern and appends either the matching token
+    /// or `TIMING_PLACEHOLDER` to `word`, then clears `letter` so the next keyed run starts fresh.
+    /// A no-op if no keyed run has accumulated (e.g. two consecutive letter gaps).
+    fn finish_timed_letter(letter: &mut Vec<Morse>, word: &mut String) {
+        if letter.is_empty() {
+            return;
+        }
+        let pattern = std::mem::take(letter);
+        match token_from_morse_pattern(&pattern) {
+            Some(token) => word.push_str(token),
+            None => word.push_str(TIMING_PLACEHOLDER),
+        }
+    }
+
+    /// Decodes raw keying timings the way a keyer/receiver would: each `(key-down?, duration_ms)`
+    /// event is an on or off span of a real (possibly noisy) signal, rather than pre-segmented
+    /// symbol text. Dit/dah length and element/letter/word gap length are not known in advance,
+    /// so both are estimated adaptively by clustering the observed key-down durations into two
+    /// buckets (`Dit`, `Dah`) and the observed key-up durations into three (element gap, letter
+    /// gap, word gap) via `cluster_thresholds`, instead of assuming a fixed WPM. A keyed run whose
+    /// resulting pattern doesn't match any known token is substituted with `TIMING_PLACEHOLDER`
+    /// rather than aborting, resynchronizing at the next letter gap so one bad run doesn't lose
+    /// the rest of the message.
+    pub fn decode_timings(events: &[(bool, u32)]) -> String {
+        let key_down_durations: Vec<u32> = events.iter().filter(|(keyed, _)| *keyed).map(|(_, d)| *d).collect();
+        let key_up_durations: Vec<u32> = events.iter().filter(|(keyed, _)| !*keyed).map(|(_, d)| *d).collect();
+        let dit_dah_thresholds = cluster_thresholds(&key_down_durations, 2);
+        let gap_thresholds = cluster_thresholds(&key_up_durations, 3);
+
+        let mut letter: Vec<Morse> = Vec::new();
+        let mut words: Vec<String> = Vec::new();
+        let mut word = String::new();
+
+        for &(keyed, duration) in events {
+            if keyed {
+                letter.push(classify_dit_dah(duration, &dit_dah_thresholds, &key_up_durations));
+            } else {
+                let gap_class = classify(duration, &gap_thresholds);
+                if gap_class >= 1 {
+                    finish_timed_letter(&mut letter, &mut word);
+                }
+                if gap_class >= 2 {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+        }
+        finish_timed_letter(&mut letter, &mut word);
+        words.push(word);
+        words.join(" ")
     }
 }
 
@@ -183,8 +741,126 @@ mod morse_test {
     #[test]
     fn test_encode_eq_decode() {
         let msg_str = "hello there friend";
-        let msg_morse = morse::translate_to_morse(msg_str);
-        let msg_back_to_str = morse::translate_from_morse(&msg_morse);
+        let msg_morse = morse::translate_to_morse(msg_str, morse::Notation::Unicode).unwrap();
+        let msg_back_to_str = morse::translate_from_morse(&msg_morse, morse::Notation::Unicode).unwrap();
+        assert_eq!(msg_str.trim(), msg_back_to_str.trim())
+    }
+
+    #[test]
+    fn test_encode_eq_decode_ditdah() {
+        let msg_str = "hello there friend";
+        let msg_morse = morse::translate_to_morse(msg_str, morse::Notation::DitDah).unwrap();
+        let msg_back_to_str = morse::translate_from_morse(&msg_morse, morse::Notation::DitDah).unwrap();
         assert_eq!(msg_str.trim(), msg_back_to_str.trim())
     }
+
+    #[test]
+    fn test_encode_eq_decode_binary() {
+        let msg_str = "hello there friend";
+        let notation = morse::Notation::Binary { big_endian: true };
+        let msg_morse = morse::translate_to_morse(msg_str, notation).unwrap();
+        let msg_back_to_str = morse::translate_from_morse(&msg_morse, notation).unwrap();
+        assert_eq!(msg_str.trim(), msg_back_to_str.trim())
+    }
+
+    #[test]
+    fn test_encode_unknown_character_fails() {
+        let err = morse::translate_to_morse("hello!", morse::Notation::Unicode).unwrap_err();
+        assert_eq!(err.token, "!");
+        assert_eq!(err.byte_offset, 5);
+    }
+
+    #[test]
+    fn test_encode_unknown_character_lossy() {
+        let morse = morse::translate_to_morse_lossy("a!a", morse::Notation::DitDah);
+        assert_eq!(morse, ".- ........ .-");
+    }
+
+    #[test]
+    fn test_decode_malformed_group_fails() {
+        let err = morse::translate_from_morse("..-- .-", morse::Notation::DitDah).unwrap_err();
+        assert_eq!(err.byte_offset, 0);
+    }
+
+    #[test]
+    fn test_decode_odd_length_binary_fails() {
+        let notation = morse::Notation::Binary { big_endian: true };
+        let err = morse::translate_from_morse("abc", notation).unwrap_err();
+        assert_eq!(err.byte_offset, 2);
+    }
+
+    #[test]
+    fn test_decode_odd_length_binary_lossy() {
+        let notation = morse::Notation::Binary { big_endian: true };
+        let msg = morse::translate_from_morse_lossy("abc", notation, "?");
+        assert_eq!(msg, "?");
+    }
+
+    #[test]
+    fn test_decode_malformed_group_lossy() {
+        let msg = morse::translate_from_morse_lossy("..-- .-", morse::Notation::DitDah, "?");
+        assert_eq!(msg, "?a");
+    }
+
+    #[test]
+    fn test_encode_eq_decode_punctuation() {
+        let msg_str = "wait, over? <ar>";
+        let msg_morse = morse::translate_to_morse(msg_str, morse::Notation::DitDah).unwrap();
+        let msg_back_to_str = morse::translate_from_morse(&msg_morse, morse::Notation::DitDah).unwrap();
+        assert_eq!(msg_str.trim(), msg_back_to_str.trim())
+    }
+
+    #[test]
+    fn test_prosign_has_no_inter_letter_gap() {
+        let msg_morse = morse::translate_to_morse("<sk>", morse::Notation::DitDah).unwrap();
+        assert_eq!(msg_morse, "...-.-");
+    }
+
+    /// Builds `(key-down?, duration_ms)` events for `msg` at a fixed `dit_ms`, the way a perfectly
+    /// steady keyer would, so `decode_timings` has something deterministic to cluster.
+    fn timed_events(msg: &str, dit_ms: u32) -> Vec<(bool, u32)> {
+        let ditdah = morse::translate_to_morse(msg, morse::Notation::DitDah).unwrap();
+        let mut events: Vec<(bool, u32)> = Vec::new();
+        for c in ditdah.chars() {
+            match c {
+                '.' => events.push((true, dit_ms)),
+                '-' => events.push((true, dit_ms * 3)),
+                ' ' => events.push((false, dit_ms * 3)),
+                '/' => events.push((false, dit_ms * 7)),
+                _ => {}
+            }
+            if matches!(c, '.' | '-') {
+                events.push((false, dit_ms));
+            }
+        }
+        events
+    }
+
+    #[test]
+    fn test_decode_timings_roundtrip() {
+        let events = timed_events("hello there friend", 50);
+        assert_eq!(morse::decode_timings(&events), "hello there friend");
+    }
+
+    #[test]
+    fn test_decode_timings_resyncs_after_bad_run() {
+        let mut events = timed_events("sos", 50);
+        // Splice three stray dits in front of the first letter with no intervening letter gap, so
+        // they run together with "s"'s own three dits into an unmapped six-dit pattern.
+        for _ in 0..3 {
+            events.insert(0, (false, 50));
+            events.insert(0, (true, 50));
+        }
+        let decoded = morse::decode_timings(&events);
+        assert!(decoded.ends_with("os"));
+        assert!(decoded.starts_with("?"));
+    }
+
+    #[test]
+    fn test_decode_timings_all_dahs() {
+        // "mom" is every letter's key-down run the same length (all dahs), so there's no second
+        // cluster of key-down durations to split Dit from Dah on.
+        let events = timed_events("mom", 50);
+        assert_eq!(morse::decode_timings(&events), "mom");
+    }
 }
@@ -11,6 +11,25 @@ enum Mode {
     Decode
 }
 
+/// CLI-facing mirror of `secret_message::morse::Notation`; endianness for `Binary` is taken from
+/// the separate `--little-endian` flag instead of being folded into this enum.
+#[derive(Copy, Clone, PartialEq, Debug, ValueEnum)]
+enum NotationArg {
+    Unicode,
+    DitDah,
+    Binary,
+}
+
+impl NotationArg {
+    fn into_notation(self, little_endian: bool) -> Notation {
+        match self {
+            NotationArg::Unicode => Notation::Unicode,
+            NotationArg::DitDah => Notation::DitDah,
+            NotationArg::Binary => Notation::Binary { big_endian: !little_endian },
+        }
+    }
+}
+
 /// A simple program for encoding to and decoding from morse code
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
@@ -33,7 +52,34 @@ struct Args {
     /// If given the program will try to write it into the file at this path. If the file does not
     /// exist the program will attempt to create it.
     #[clap(short, long, value_parser)]
-    output: Option<PathBuf>
+    output: Option<PathBuf>,
+    /// If given (encode mode only) the program will render the morse as a CW audio tone and
+    /// write it as a WAV file to this path, in addition to the text output.
+    #[clap(short, long, value_parser)]
+    audio: Option<PathBuf>,
+    /// Keying speed of the rendered audio, in words per minute (PARIS standard)
+    #[clap(long, value_parser, default_value_t = 20)]
+    wpm: u16,
+    /// Sidetone frequency of the rendered audio, in Hz
+    #[clap(long, value_parser, default_value_t = 600.0)]
+    tone_hz: f32,
+    /// Sample rate of the rendered audio, in Hz
+    #[clap(long, value_parser, default_value_t = 44100)]
+    sample_rate: u32,
+    /// The symbol notation to emit (encode) or expect (decode)
+    #[clap(short, long, value_parser, arg_enum, default_value = "unicode")]
+    notation: NotationArg,
+    /// Pack `Binary` notation bytes LSB-first instead of the default MSB-first
+    #[clap(long, value_parser)]
+    little_endian: bool,
+    /// If given, an unrecognized character or morse group is replaced by a placeholder instead
+    /// of aborting the translation
+    #[clap(long, value_parser)]
+    lossy: bool,
+    /// The placeholder substituted for unrecognized morse groups in decode mode when `--lossy`
+    /// is given
+    #[clap(long, value_parser, default_value = "?")]
+    placeholder: String,
 }
 
 fn read_file(path: &PathBuf) -> String {
@@ -49,6 +95,30 @@ fn write_to_file(path: &PathBuf, msg: &str) {
     fs::write(path, msg).expect("Unable to write to file");
 }
 
+/// Writes `samples` out as a mono 16-bit PCM WAV file at `sample_rate`.
+fn write_wav(path: &PathBuf, samples: &[i16], sample_rate: u32) {
+    let data_size = (samples.len() * 2) as u32;
+    let byte_rate = sample_rate * 2;
+    let mut buf: Vec<u8> = Vec::with_capacity(44 + data_size as usize);
+    buf.extend_from_slice(b"RIFF");
+    buf.extend_from_slice(&(36 + data_size).to_le_bytes());
+    buf.extend_from_slice(b"WAVE");
+    buf.extend_from_slice(b"fmt ");
+    buf.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
+    buf.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    buf.extend_from_slice(&1u16.to_le_bytes()); // mono
+    buf.extend_from_slice(&sample_rate.to_le_bytes());
+    buf.extend_from_slice(&byte_rate.to_le_bytes());
+    buf.extend_from_slice(&2u16.to_le_bytes()); // block align
+    buf.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    buf.extend_from_slice(b"data");
+    buf.extend_from_slice(&data_size.to_le_bytes());
+    for sample in samples {
+        buf.extend_from_slice(&sample.to_le_bytes());
+    }
+    fs::write(path, buf).expect("Unable to write audio file");
+}
+
 fn main() {
     let args = Args::parse();
     let msg = if args.message.is_some() {
@@ -57,23 +127,54 @@ fn main() {
         let path: &PathBuf = &args.source_file.unwrap();
         read_file(path)
     };
+    let notation = args.notation.into_notation(args.little_endian);
     let regex: regex::Regex = match args.mode {
-        Mode::Encode => regex::Regex::new(r"^[a-zA-Z0-9\s]*$").expect("Unable to create regex"), // encode
+        Mode::Encode => regex::Regex::new(r"^[a-zA-Z0-9\s.,?/<>]*$").expect("Unable to create regex"), // encode
                                                                                                  // allowed
-                                                                                                 // chars
-        Mode::Decode => regex::Regex::new(r"^[·―\s]*$").expect("Unable to create regex") // Decode
-                                                                                         // allowed
-                                                                                         // chars
+                                                                                                 // chars (incl.
+                                                                                                 // punctuation and
+                                                                                                 // <prosign> tokens)
+        Mode::Decode => match args.notation {
+            NotationArg::Unicode => regex::Regex::new(r"^[·―\s]*$").expect("Unable to create regex"),
+            NotationArg::DitDah => regex::Regex::new(r"^[.\-\s/]*$").expect("Unable to create regex"),
+            NotationArg::Binary => regex::Regex::new(r"^([0-9a-fA-F]{2})*$").expect("Unable to create regex"),
+        }
     };
-    if !regex.is_match(&msg) {
+    if !regex.is_match(msg.trim()) {
         eprintln!("The given message contains unallowed characters");
         std::process::exit(1)
     }
     let msg = msg.to_lowercase();
-    let translated = match args.mode {
-        Mode::Encode => translate_to_morse(msg.trim()),
-        Mode::Decode => translate_from_morse(msg.trim()),
+    let translated = if args.lossy {
+        match args.mode {
+            Mode::Encode => translate_to_morse_lossy(msg.trim(), notation),
+            Mode::Decode => translate_from_morse_lossy(msg.trim(), notation, &args.placeholder),
+        }
+    } else {
+        let result = match args.mode {
+            Mode::Encode => translate_to_morse(msg.trim(), notation),
+            Mode::Decode => translate_from_morse(msg.trim(), notation),
+        };
+        match result {
+            Ok(translated) => translated,
+            Err(err) => {
+                eprintln!("{}", err);
+                std::process::exit(1)
+            }
+        }
     };
+    if let Some(audio) = args.audio {
+        if args.mode != Mode::Encode {
+            eprintln!("--audio is only supported in encode mode");
+            std::process::exit(1)
+        }
+        if args.wpm == 0 {
+            eprintln!("--wpm must be greater than 0");
+            std::process::exit(1)
+        }
+        let samples = synthesize(msg.trim(), args.wpm, args.tone_hz, args.sample_rate);
+        write_wav(&audio, &samples, args.sample_rate);
+    }
     if let Some(output) = args.output {
         write_to_file(&output, translated.trim())
     } else {